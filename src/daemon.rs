@@ -0,0 +1,72 @@
+use chrono::{Duration, LocalResult, NaiveTime, TimeZone, Utc};
+use chrono_tz::Tz;
+use std::time::Duration as StdDuration;
+
+use crate::{error::Error, run_once, state::Store, Config, OutputFormat};
+
+/// Brings up a `tokio` runtime and runs `run_once` once a day at
+/// `cfg.schedule.time` (in `cfg.schedule.timezone`), reloading the CSV on
+/// every tick so edits take effect without restarting the process.
+pub fn run(cfg: Config, store: Store, output: OutputFormat) -> Result<(), Error> {
+    let tz: Tz = cfg
+        .schedule
+        .timezone
+        .parse()
+        .map_err(|_| Error::Config(format!("invalid schedule.timezone \"{}\"", cfg.schedule.timezone)))?;
+    let fire_time = NaiveTime::parse_from_str(&cfg.schedule.time, "%H:%M").map_err(|err| {
+        Error::Config(format!(
+            "invalid schedule.time \"{}\" ({})",
+            cfg.schedule.time, err
+        ))
+    })?;
+
+    let runtime = tokio::runtime::Runtime::new()?;
+    runtime.block_on(async move {
+        loop {
+            let sleep_for = match time_until_next_fire(fire_time, tz) {
+                Ok(duration) => duration,
+                Err(err) => {
+                    eprintln!("Warning: {}, retrying in an hour", err);
+                    StdDuration::from_secs(3600)
+                }
+            };
+            tokio::time::sleep(sleep_for).await;
+            if let Err(err) = run_once(&cfg, &store, false, output) {
+                eprintln!("Error: daily run failed ({}), will retry at the next tick", err);
+            }
+        }
+    })
+}
+
+/// How long to sleep before the next `fire_time` in `tz`, rolling over to
+/// tomorrow if that time has already passed today. Prefers the earlier
+/// instant on a DST-overlap (ambiguous) local time, and nudges an hour
+/// later on a DST-gap (nonexistent) local time.
+fn time_until_next_fire(fire_time: NaiveTime, tz: Tz) -> Result<StdDuration, Error> {
+    let now = Utc::now().with_timezone(&tz);
+    let mut next_date = now.date_naive();
+    if now.time() >= fire_time {
+        next_date += Duration::days(1);
+    }
+
+    let next_fire = match tz.from_local_datetime(&next_date.and_time(fire_time)) {
+        LocalResult::Single(dt) => dt,
+        LocalResult::Ambiguous(earliest, _latest) => earliest,
+        LocalResult::None => {
+            match tz.from_local_datetime(&(next_date.and_time(fire_time) + Duration::hours(1))) {
+                LocalResult::Single(dt) => dt,
+                LocalResult::Ambiguous(earliest, _latest) => earliest,
+                LocalResult::None => {
+                    return Err(Error::Config(format!(
+                        "schedule.time {} does not exist in {} around {}",
+                        fire_time, tz, next_date
+                    )))
+                }
+            }
+        }
+    };
+
+    Ok((next_fire.with_timezone(&Utc) - Utc::now())
+        .to_std()
+        .unwrap_or(StdDuration::from_secs(0)))
+}
@@ -0,0 +1,65 @@
+use std::fmt;
+
+/// Crate-wide error type.
+#[derive(Debug)]
+pub enum Error {
+    Config(String),
+    Csv(csv::Error),
+    Http(reqwest::Error),
+    Io(std::io::Error),
+    State(sled::Error),
+    SlackStatus(reqwest::StatusCode),
+    SlackApi(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Config(msg) => write!(f, "configuration error: {}", msg),
+            Error::Csv(err) => write!(f, "CSV error: {}", err),
+            Error::Http(err) => write!(f, "HTTP error: {}", err),
+            Error::Io(err) => write!(f, "I/O error: {}", err),
+            Error::State(err) => write!(f, "state database error: {}", err),
+            Error::SlackStatus(status) => write!(f, "Slack API returned {}", status),
+            Error::SlackApi(msg) => write!(f, "Slack API returned ok: false ({})", msg),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<csv::Error> for Error {
+    fn from(err: csv::Error) -> Self {
+        Error::Csv(err)
+    }
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(err: reqwest::Error) -> Self {
+        Error::Http(err)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(err: std::io::Error) -> Self {
+        Error::Io(err)
+    }
+}
+
+impl From<sled::Error> for Error {
+    fn from(err: sled::Error) -> Self {
+        Error::State(err)
+    }
+}
+
+impl From<toml::de::Error> for Error {
+    fn from(err: toml::de::Error) -> Self {
+        Error::Config(err.to_string())
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(err: serde_json::Error) -> Self {
+        Error::Config(err.to_string())
+    }
+}
@@ -1,17 +1,56 @@
-use chrono::{DateTime, Datelike, Duration, Utc};
+//! Bad input (malformed CSV rows, failed Slack sends) is logged and
+//! skipped/counted rather than aborting the run; only config/IO failures
+//! that make a run impossible propagate as `Err`.
+
+use chrono::{Datelike, Duration, Utc};
+use chrono_tz::Tz;
 use colored::Colorize;
 use csv::{DeserializeRecordsIter, Reader};
 use reqwest::blocking;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::fs;
-use std::path::PathBuf;
-use toml;
+use std::path::{Path, PathBuf};
+
+mod daemon;
+mod error;
+mod slack;
+mod state;
+
+use error::Error;
 
 #[derive(Deserialize)]
 struct Config {
     warning: Warning,
-    csv: CSV,
+    csv: Csv,
     slack: Slack,
+    messages: Messages,
+    state: State,
+    schedule: Schedule,
+}
+
+#[derive(Deserialize)]
+struct State {
+    path: PathBuf,
+}
+
+#[derive(Deserialize)]
+struct Schedule {
+    /// Run as a long-lived daemon that wakes once a day instead of
+    /// exiting after a single pass.
+    enabled: bool,
+    /// Local time of day (`HH:MM`) to run the daily check, in `timezone`.
+    time: String,
+    /// IANA timezone (e.g. `Europe/London`) "today" is computed in, both
+    /// for date matching and for scheduling the daily wake-up.
+    timezone: String,
+}
+
+#[derive(Deserialize)]
+struct Messages {
+    birthday_single: String,
+    birthday_double: String,
+    birthday_multiple: String,
+    warning: String,
 }
 
 #[derive(Deserialize)]
@@ -19,6 +58,13 @@ struct Slack {
     enabled: bool,
     channel_id: Option<String>,
     webhook_url: Option<String>,
+    /// Bot token used to resolve CSV tags to member IDs via `users.list`,
+    /// and, if `update_status` is set, to set the birthday 🎉 status.
+    bot_token: Option<String>,
+    /// Only takes effect when `enabled` is also set, so turning Slack off
+    /// (e.g. for a dry run) doesn't leave live status updates running.
+    #[serde(default)]
+    update_status: bool,
 }
 #[derive(Deserialize)]
 struct Warning {
@@ -29,10 +75,18 @@ struct Warning {
 }
 
 #[derive(Deserialize)]
-struct CSV {
+struct Csv {
     path: PathBuf,
     date_separator: char,
     date_format: DateFormat,
+    /// Whether a birthday on Feb 29 should also fire on Feb 28 in years
+    /// that aren't leap years.
+    #[serde(default = "default_true")]
+    leap_day_fallback: bool,
+}
+
+fn default_true() -> bool {
+    true
 }
 
 #[derive(Deserialize, PartialEq)]
@@ -40,6 +94,10 @@ struct CSV {
 enum DateFormat {
     MonthDay,
     DayMonth,
+    /// `YYYY-MM-DD`, allowing the renderer to compute `{age}`.
+    YearMonthDay,
+    /// `DD-MM-YYYY`, allowing the renderer to compute `{age}`.
+    DayMonthYear,
 }
 
 #[derive(Debug, Deserialize, PartialEq)]
@@ -47,110 +105,535 @@ enum DateFormat {
 struct BirthdayRow {
     date: String,
     tag: String,
+    /// Parsed out of `date` when `date_format` includes a year.
+    #[serde(skip)]
+    birth_year: Option<i32>,
+}
+
+/// Picks the config file to load: `--config <path>` if given, otherwise
+/// `config.json` if it exists, else the original `config.toml`.
+fn find_config_path() -> PathBuf {
+    let args: Vec<String> = std::env::args().collect();
+    if let Some(path) = args
+        .iter()
+        .position(|arg| arg == "--config")
+        .and_then(|idx| args.get(idx + 1))
+    {
+        return PathBuf::from(path);
+    }
+
+    if Path::new("config.json").exists() {
+        PathBuf::from("config.json")
+    } else {
+        PathBuf::from("config.toml")
+    }
 }
 
-fn read_config() -> Config {
-    let contents = fs::read_to_string("config.toml").expect("Failed to read config.toml");
-    toml::from_str(&contents).expect("Unable to deserialize config.toml")
+/// Reads and parses `path`, picking the format (TOML or JSON) by its file
+/// extension.
+fn read_config(path: &Path) -> Result<Config, Error> {
+    let contents = fs::read_to_string(path)?;
+    match path.extension().and_then(|ext| ext.to_str()) {
+        Some("json") => Ok(serde_json::from_str(&contents)?),
+        _ => Ok(toml::from_str(&contents)?),
+    }
+}
+
+/// How `run_once` reports what it found: human-readable Slack-flavoured
+/// text, or a structured JSON document for embedding in other pipelines.
+#[derive(Clone, Copy, PartialEq)]
+enum OutputFormat {
+    Text,
+    Json,
 }
 
-fn get_date_str(epoch_time: DateTime<Utc>, cfg: &CSV) -> String {
-    let month = epoch_time.month();
-    let day = epoch_time.day();
+/// Parses `--output json`/`--output text` from argv, defaulting to `Text`.
+fn find_output_format() -> OutputFormat {
+    let args: Vec<String> = std::env::args().collect();
+    let value = args
+        .iter()
+        .position(|arg| arg == "--output")
+        .and_then(|idx| args.get(idx + 1));
+
+    match value.map(String::as_str) {
+        Some("json") => OutputFormat::Json,
+        _ => OutputFormat::Text,
+    }
+}
+
+#[derive(Serialize)]
+struct JsonEntry {
+    tag: String,
+    member_id: String,
+    date: String,
+    age: Option<i32>,
+}
+
+#[derive(Serialize)]
+struct JsonOutput {
+    current: Vec<JsonEntry>,
+    upcoming: Vec<JsonEntry>,
+}
 
-    if cfg.date_format == DateFormat::MonthDay {
-        format!("{:0>2}{}{:0>2}", month, cfg.date_separator, day)
+/// Splits a CSV `date` value into `(month, day, birth_year)` according to
+/// `cfg`'s separator and format. `birth_year` is `None` for the two
+/// year-less formats.
+fn parse_date_parts(date: &str, cfg: &Csv) -> Option<(u32, u32, Option<i32>)> {
+    let parts: Vec<&str> = date.split(cfg.date_separator).collect();
+    match (&cfg.date_format, parts.as_slice()) {
+        (DateFormat::MonthDay, [m, d]) => Some((m.parse().ok()?, d.parse().ok()?, None)),
+        (DateFormat::DayMonth, [d, m]) => Some((m.parse().ok()?, d.parse().ok()?, None)),
+        (DateFormat::YearMonthDay, [y, m, d]) => {
+            Some((m.parse().ok()?, d.parse().ok()?, Some(y.parse().ok()?)))
+        }
+        (DateFormat::DayMonthYear, [d, m, y]) => {
+            Some((m.parse().ok()?, d.parse().ok()?, Some(y.parse().ok()?)))
+        }
+        _ => None,
+    }
+}
+
+fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+/// For a Feb 29 birthday in a non-leap `year`, returns Feb 28 instead (when
+/// `cfg.leap_day_fallback` is set) so the notification still fires.
+fn fallback_month_day(month: u32, day: u32, year: i32, cfg: &Csv) -> (u32, u32) {
+    if cfg.leap_day_fallback && month == 2 && day == 29 && !is_leap_year(year) {
+        (2, 28)
     } else {
-        format!("{:0>2}{}{:0>2}", day, cfg.date_separator, month)
+        (month, day)
     }
 }
 
+/// `(current_birthdays, upcoming_birthdays, current_year, warning_year, skipped_rows)`.
+type MatchedDates = (Vec<BirthdayRow>, Vec<BirthdayRow>, i32, i32, usize);
+
+/// Matches CSV rows against today's and the warning date. `skipped_rows`
+/// counts rows dropped for being malformed or having an unparseable date.
 fn match_dates(
     iter: DeserializeRecordsIter<fs::File, BirthdayRow>,
     warning_days: u8,
-    cfg: &CSV,
-) -> (Vec<BirthdayRow>, Vec<BirthdayRow>) {
-    let cur_epoch_time = Utc::now();
-    let current_date = get_date_str(cur_epoch_time, &cfg);
-    let warning_date = get_date_str(cur_epoch_time + Duration::days(warning_days as i64), &cfg);
+    cfg: &Csv,
+    tz: Tz,
+) -> Result<MatchedDates, Error> {
+    let cur_epoch_time = Utc::now().with_timezone(&tz);
+    let warning_epoch_time = cur_epoch_time + Duration::days(warning_days as i64);
+
+    let current_year = cur_epoch_time.year();
+    let warning_year = warning_epoch_time.year();
+    let current = (cur_epoch_time.month(), cur_epoch_time.day());
+    let warning = (warning_epoch_time.month(), warning_epoch_time.day());
 
     let mut current_birthdays: Vec<BirthdayRow> = Vec::new();
     let mut upcoming_birthdays: Vec<BirthdayRow> = Vec::new();
+    let mut skipped_rows = 0;
 
     for result in iter {
-        let record: BirthdayRow = result.expect("Deserialized CSV record");
-        if record.date == current_date {
+        let mut record: BirthdayRow = match result {
+            Ok(record) => record,
+            Err(err) => {
+                eprintln!("Warning: skipping malformed CSV row ({})", err);
+                skipped_rows += 1;
+                continue;
+            }
+        };
+
+        let (month, day, birth_year) = match parse_date_parts(&record.date, cfg) {
+            Some(parts) => parts,
+            None => {
+                eprintln!(
+                    "Warning: skipping row with unparseable date \"{}\"",
+                    record.date
+                );
+                skipped_rows += 1;
+                continue;
+            }
+        };
+        record.birth_year = birth_year;
+
+        if fallback_month_day(month, day, current_year, cfg) == current {
             current_birthdays.push(record);
-        } else if record.date == warning_date {
+        } else if fallback_month_day(month, day, warning_year, cfg) == warning {
             upcoming_birthdays.push(record);
         }
     }
 
-    (current_birthdays, upcoming_birthdays)
+    Ok((
+        current_birthdays,
+        upcoming_birthdays,
+        current_year,
+        warning_year,
+        skipped_rows,
+    ))
+}
+
+/// Resolves each row's `tag` to a Slack member ID via `directory`, falling
+/// back to the raw tag (treated as a literal ID) when no directory was
+/// loaded.
+fn resolve_tags(rows: &[BirthdayRow], directory: Option<&slack::Directory>) -> Vec<String> {
+    rows.iter()
+        .map(|b| match directory {
+            Some(dir) => dir.resolve(&b.tag),
+            None => b.tag.clone(),
+        })
+        .collect()
 }
 
-fn slack_format(tags: Vec<BirthdayRow>, birthday_message: &str) -> String {
-    format!(
-        "{}<@{}>",
-        birthday_message,
-        tags.iter()
-            .map(|b| b.tag.to_string())
-            .collect::<Vec<String>>()
-            .join(">, <@")
-    )
+fn format_mentions(member_ids: &[String]) -> String {
+    member_ids
+        .iter()
+        .map(|id| format!("<@{}>", id))
+        .collect::<Vec<String>>()
+        .join(", ")
 }
 
-fn slack_send(message: &str, webhook_url: String, channel_id: String) {
+/// Ages turned by each row during `year`, comma-separated in row order.
+/// Rows with no parsed birth year are omitted.
+fn ages_turning(rows: &[BirthdayRow], year: i32) -> String {
+    rows.iter()
+        .filter_map(|b| b.birth_year.map(|birth_year| (year - birth_year).to_string()))
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+/// Renders a message template by substituting `{token}` placeholders with
+/// computed values. Tokens with no matching value are left untouched.
+fn render_template(template: &str, values: &[(&str, String)]) -> String {
+    let mut rendered = template.to_string();
+    for (token, value) in values {
+        rendered = rendered.replace(&format!("{{{}}}", token), value);
+    }
+    rendered
+}
+
+const SLACK_SEND_ATTEMPTS: u32 = 3;
+
+/// Posts `message` to a Slack incoming webhook. Retries transient (5xx)
+/// failures with exponential backoff.
+fn slack_send(message: &str, webhook_url: &str, channel_id: &str) -> Result<(), Error> {
     let payload = serde_json::json!({
         "text": message,
         "channel": channel_id,
     });
-
     let client = blocking::Client::new();
-    client.post(webhook_url).json(&payload).send().unwrap();
+
+    for attempt in 1..=SLACK_SEND_ATTEMPTS {
+        let response = client.post(webhook_url).json(&payload).send()?;
+        let status = response.status();
+        if status.is_success() {
+            return Ok(());
+        }
+        if !status.is_server_error() || attempt == SLACK_SEND_ATTEMPTS {
+            return Err(Error::SlackStatus(status));
+        }
+        eprintln!(
+            "Warning: Slack webhook returned {}, retrying (attempt {}/{})",
+            status, attempt, SLACK_SEND_ATTEMPTS
+        );
+        std::thread::sleep(std::time::Duration::from_millis(500 * 2u64.pow(attempt - 1)));
+    }
+
+    unreachable!("loop always returns by the final attempt")
 }
 
-fn main() {
-    let cfg = read_config();
-    let mut reader = Reader::from_path(&cfg.csv.path).expect("Unable to read file");
+/// Outcome of one `run_once` pass, reported by `main`.
+#[derive(Default)]
+pub(crate) struct RunSummary {
+    pub skipped_rows: usize,
+    pub failed_notifications: usize,
+}
+
+/// Runs one full pass: reloads the CSV, resolves Slack tags, matches
+/// today's and upcoming birthdays, and notifies. Called once for a
+/// one-shot run, or once per tick from `daemon::run`.
+pub(crate) fn run_once(
+    cfg: &Config,
+    store: &state::Store,
+    force: bool,
+    output: OutputFormat,
+) -> Result<RunSummary, Error> {
+    let mut reader = Reader::from_path(&cfg.csv.path)?;
+    let tz: Tz = cfg
+        .schedule
+        .timezone
+        .parse()
+        .map_err(|_| Error::Config(format!("invalid schedule.timezone \"{}\"", cfg.schedule.timezone)))?;
+    let today = Utc::now().with_timezone(&tz).format("%Y-%m-%d").to_string();
+
+    let mut summary = RunSummary::default();
+    let mut json_current: Vec<JsonEntry> = Vec::new();
+    let mut json_upcoming: Vec<JsonEntry> = Vec::new();
+
+    let directory = cfg.slack.bot_token.as_deref().and_then(|token| {
+        match slack::Directory::fetch(token) {
+            Ok(dir) => Some(dir),
+            Err(err) => {
+                eprintln!("Warning: failed to fetch Slack member list ({}), falling back to literal tags", err);
+                None
+            }
+        }
+    });
 
     let iter = reader.deserialize();
-    let (current_birthdays, upcoming_birthdays) =
-        match_dates(iter, cfg.warning.number_of_days_warning, &cfg.csv);
+    let (current_birthdays, upcoming_birthdays, current_year, warning_year, skipped_rows) =
+        match_dates(iter, cfg.warning.number_of_days_warning, &cfg.csv, tz)?;
+    summary.skipped_rows = skipped_rows;
+
+    let current_birthdays: Vec<BirthdayRow> = if force {
+        current_birthdays
+    } else {
+        current_birthdays
+            .into_iter()
+            .filter(|row| !store.was_sent(&today, &row.tag, state::NotificationKind::Current))
+            .collect()
+    };
+    let upcoming_birthdays: Vec<BirthdayRow> = if force {
+        upcoming_birthdays
+    } else {
+        upcoming_birthdays
+            .into_iter()
+            .filter(|row| !store.was_sent(&today, &row.tag, state::NotificationKind::Warning))
+            .collect()
+    };
 
     if !current_birthdays.is_empty() {
-        let birthday_message = if current_birthdays.len() == 1 {
-            "Happy birthday"
-        } else if current_birthdays.len() == 2 {
-            "Happy birthday to you both!"
-        } else {
-            "Happy birthday to you all!"
+        let member_ids = resolve_tags(&current_birthdays, directory.as_ref());
+
+        if cfg.slack.enabled && cfg.slack.update_status {
+            if let Some(bot_token) = cfg.slack.bot_token.as_deref() {
+                for member_id in &member_ids {
+                    if let Err(err) = slack::Directory::set_birthday_status(bot_token, member_id) {
+                        eprintln!("Warning: failed to set birthday status for {} ({})", member_id, err);
+                    }
+                }
+            }
+        }
+
+        let template = match current_birthdays.len() {
+            1 => &cfg.messages.birthday_single,
+            2 => &cfg.messages.birthday_double,
+            _ => &cfg.messages.birthday_multiple,
         };
+        let message = render_template(
+            template,
+            &[
+                ("mentions", format_mentions(&member_ids)),
+                ("count", current_birthdays.len().to_string()),
+                ("age", ages_turning(&current_birthdays, current_year)),
+            ],
+        );
+
+        if output == OutputFormat::Text {
+            println!("{}", &message.yellow());
+        }
+        for (row, member_id) in current_birthdays.iter().zip(member_ids.iter()) {
+            json_current.push(JsonEntry {
+                tag: row.tag.clone(),
+                member_id: member_id.clone(),
+                date: row.date.clone(),
+                age: row.birth_year.map(|birth_year| current_year - birth_year),
+            });
+        }
 
-        let message = slack_format(current_birthdays, birthday_message);
-        println!("{}", &message.yellow());
         if cfg.slack.enabled {
-            if let (Some(url), Some(channel_id)) = (cfg.slack.webhook_url, cfg.slack.channel_id) {
-                slack_send(&message, url, channel_id);
+            if let (Some(url), Some(channel_id)) =
+                (cfg.slack.webhook_url.as_deref(), cfg.slack.channel_id.as_deref())
+            {
+                match slack_send(&message, url, channel_id) {
+                    Ok(()) => {
+                        for row in &current_birthdays {
+                            store.mark_sent(&today, &row.tag, state::NotificationKind::Current);
+                        }
+                    }
+                    Err(err) => {
+                        eprintln!("Warning: failed to send birthday notification ({})", err);
+                        summary.failed_notifications += 1;
+                    }
+                }
             }
         };
     }
 
     if !upcoming_birthdays.is_empty() {
-        let message = slack_format(
-            upcoming_birthdays,
-            format!(
-                "Birthdays {} days from now: ",
-                cfg.warning.number_of_days_warning
-            )
-            .as_str(),
+        let member_ids = resolve_tags(&upcoming_birthdays, directory.as_ref());
+        let message = render_template(
+            &cfg.messages.warning,
+            &[
+                ("mentions", format_mentions(&member_ids)),
+                ("count", upcoming_birthdays.len().to_string()),
+                ("days", cfg.warning.number_of_days_warning.to_string()),
+                ("age", ages_turning(&upcoming_birthdays, warning_year)),
+            ],
         );
-        println!("{}", &message.yellow());
+
+        if output == OutputFormat::Text {
+            println!("{}", &message.yellow());
+        }
+        for (row, member_id) in upcoming_birthdays.iter().zip(member_ids.iter()) {
+            json_upcoming.push(JsonEntry {
+                tag: row.tag.clone(),
+                member_id: member_id.clone(),
+                date: row.date.clone(),
+                age: row.birth_year.map(|birth_year| warning_year - birth_year),
+            });
+        }
+
         if cfg.warning.enabled {
-            if let (Some(url), Some(channel_id)) = (cfg.warning.webhook_url, cfg.warning.channel_id)
-            {
-                slack_send(&message, url, channel_id);
+            if let (Some(url), Some(channel_id)) = (
+                cfg.warning.webhook_url.as_deref(),
+                cfg.warning.channel_id.as_deref(),
+            ) {
+                match slack_send(&message, url, channel_id) {
+                    Ok(()) => {
+                        for row in &upcoming_birthdays {
+                            store.mark_sent(&today, &row.tag, state::NotificationKind::Warning);
+                        }
+                    }
+                    Err(err) => {
+                        eprintln!("Warning: failed to send upcoming-birthday notification ({})", err);
+                        summary.failed_notifications += 1;
+                    }
+                }
             }
         };
     }
+
+    if output == OutputFormat::Json {
+        let doc = JsonOutput {
+            current: json_current,
+            upcoming: json_upcoming,
+        };
+        match serde_json::to_string(&doc) {
+            Ok(json) => println!("{}", json),
+            Err(err) => eprintln!("Warning: failed to serialize JSON output ({})", err),
+        }
+    }
+
+    Ok(summary)
+}
+
+fn main() {
+    let force = std::env::args().any(|arg| arg == "--force");
+    let output = find_output_format();
+
+    let cfg = match read_config(&find_config_path()) {
+        Ok(cfg) => cfg,
+        Err(err) => {
+            eprintln!("Fatal: {}", err);
+            std::process::exit(1);
+        }
+    };
+    let store = match state::Store::open(&cfg.state.path) {
+        Ok(store) => store,
+        Err(err) => {
+            eprintln!("Fatal: unable to open state database ({})", err);
+            std::process::exit(1);
+        }
+    };
+
+    if cfg.schedule.enabled {
+        if let Err(err) = daemon::run(cfg, store, output) {
+            eprintln!("Fatal: {}", err);
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    match run_once(&cfg, &store, force, output) {
+        Ok(summary) => {
+            if summary.skipped_rows > 0 || summary.failed_notifications > 0 {
+                eprintln!(
+                    "Summary: {} row(s) skipped, {} notification(s) failed to send",
+                    summary.skipped_rows, summary.failed_notifications
+                );
+            }
+        }
+        Err(err) => {
+            eprintln!("Fatal: {}", err);
+            std::process::exit(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn csv_cfg(date_format: DateFormat) -> Csv {
+        Csv {
+            path: PathBuf::new(),
+            date_separator: '-',
+            date_format,
+            leap_day_fallback: true,
+        }
+    }
+
+    #[test]
+    fn parse_date_parts_month_day() {
+        let cfg = csv_cfg(DateFormat::MonthDay);
+        assert_eq!(parse_date_parts("2-29", &cfg), Some((2, 29, None)));
+    }
+
+    #[test]
+    fn parse_date_parts_day_month() {
+        let cfg = csv_cfg(DateFormat::DayMonth);
+        assert_eq!(parse_date_parts("29-2", &cfg), Some((2, 29, None)));
+    }
+
+    #[test]
+    fn parse_date_parts_year_month_day() {
+        let cfg = csv_cfg(DateFormat::YearMonthDay);
+        assert_eq!(parse_date_parts("2000-2-29", &cfg), Some((2, 29, Some(2000))));
+    }
+
+    #[test]
+    fn parse_date_parts_day_month_year() {
+        let cfg = csv_cfg(DateFormat::DayMonthYear);
+        assert_eq!(parse_date_parts("29-2-2000", &cfg), Some((2, 29, Some(2000))));
+    }
+
+    #[test]
+    fn parse_date_parts_rejects_wrong_arity() {
+        let cfg = csv_cfg(DateFormat::MonthDay);
+        assert_eq!(parse_date_parts("2-29-2000", &cfg), None);
+    }
+
+    #[test]
+    fn fallback_month_day_leap_year_is_unchanged() {
+        let cfg = csv_cfg(DateFormat::MonthDay);
+        assert_eq!(fallback_month_day(2, 29, 2000, &cfg), (2, 29));
+    }
+
+    #[test]
+    fn fallback_month_day_non_leap_year_falls_back_to_feb_28() {
+        let cfg = csv_cfg(DateFormat::MonthDay);
+        assert_eq!(fallback_month_day(2, 29, 2001, &cfg), (2, 28));
+    }
+
+    #[test]
+    fn fallback_month_day_disabled_keeps_feb_29() {
+        let mut cfg = csv_cfg(DateFormat::MonthDay);
+        cfg.leap_day_fallback = false;
+        assert_eq!(fallback_month_day(2, 29, 2001, &cfg), (2, 29));
+    }
+
+    #[test]
+    fn render_template_substitutes_known_tokens() {
+        let rendered = render_template(
+            "Happy birthday {mentions}, turning {age}!",
+            &[
+                ("mentions", "<@U1>".to_string()),
+                ("age", "30".to_string()),
+            ],
+        );
+        assert_eq!(rendered, "Happy birthday <@U1>, turning 30!");
+    }
+
+    #[test]
+    fn render_template_leaves_unknown_tokens_untouched() {
+        let rendered = render_template("Hello {unknown}", &[("mentions", "<@U1>".to_string())]);
+        assert_eq!(rendered, "Hello {unknown}");
+    }
 }
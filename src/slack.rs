@@ -0,0 +1,144 @@
+use reqwest::blocking::Client;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+use crate::error::Error;
+
+/// Maps Slack display name / real name / email to a member ID, built once
+/// from `users.list` so the CSV can reference people by name instead of a
+/// raw Slack member ID.
+pub struct Directory {
+    by_name: HashMap<String, String>,
+}
+
+impl Directory {
+    /// Fetches the full workspace member list via the Slack Web API,
+    /// following `response_metadata.next_cursor` until it's exhausted so
+    /// workspaces with more members than fit on one page aren't silently
+    /// truncated, and indexes each user by display name, real name, and
+    /// email.
+    pub fn fetch(bot_token: &str) -> Result<Self, Error> {
+        let client = Client::new();
+        let mut by_name = HashMap::new();
+        let mut cursor = String::new();
+
+        loop {
+            let mut request = client
+                .get("https://slack.com/api/users.list")
+                .bearer_auth(bot_token);
+            if !cursor.is_empty() {
+                request = request.query(&[("cursor", &cursor)]);
+            }
+            let resp: UsersListResponse = request.send()?.json()?;
+            if !resp.ok {
+                return Err(Error::SlackApi(
+                    resp.error.unwrap_or_else(|| "unknown error".to_string()),
+                ));
+            }
+
+            for member in resp.members {
+                if let Some(name) = member.profile.display_name.filter(|s| !s.is_empty()) {
+                    by_name.insert(name, member.id.clone());
+                }
+                if let Some(name) = member.profile.real_name.filter(|s| !s.is_empty()) {
+                    by_name.insert(name, member.id.clone());
+                }
+                if let Some(email) = member.profile.email {
+                    by_name.insert(email, member.id.clone());
+                }
+            }
+
+            cursor = resp
+                .response_metadata
+                .map(|meta| meta.next_cursor)
+                .unwrap_or_default();
+            if cursor.is_empty() {
+                break;
+            }
+        }
+
+        Ok(Directory { by_name })
+    }
+
+    /// Resolves a CSV `tag` to a Slack member ID. Falls back to treating the
+    /// tag as a literal ID when no match is found, printing a warning so the
+    /// maintainer can fix the CSV instead of silently mentioning the wrong
+    /// person (or nobody).
+    pub fn resolve(&self, tag: &str) -> String {
+        match self.by_name.get(tag) {
+            Some(id) => id.clone(),
+            None => {
+                eprintln!(
+                    "Warning: could not resolve Slack user \"{}\" by name/email, treating it as a literal member ID",
+                    tag
+                );
+                tag.to_string()
+            }
+        }
+    }
+
+    /// Gives a member the 🎉 status for the day; the status expires 24 hours
+    /// after it is set. Best-effort: birthday notifications should still go
+    /// out even if this fails.
+    pub fn set_birthday_status(bot_token: &str, member_id: &str) -> Result<(), Error> {
+        let client = Client::new();
+        let payload = serde_json::json!({
+            "user": member_id,
+            "profile": {
+                "status_text": "Celebrating a birthday!",
+                "status_emoji": ":tada:",
+                "status_expiration": (chrono::Utc::now() + chrono::Duration::days(1)).timestamp(),
+            }
+        });
+
+        let resp: ApiResponse = client
+            .post("https://slack.com/api/users.profile.set")
+            .bearer_auth(bot_token)
+            .json(&payload)
+            .send()?
+            .json()?;
+
+        if !resp.ok {
+            return Err(Error::SlackApi(
+                resp.error.unwrap_or_else(|| "unknown error".to_string()),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Deserialize)]
+struct UsersListResponse {
+    ok: bool,
+    error: Option<String>,
+    #[serde(default)]
+    members: Vec<Member>,
+    response_metadata: Option<ResponseMetadata>,
+}
+
+#[derive(Deserialize)]
+struct ResponseMetadata {
+    next_cursor: String,
+}
+
+/// Shared shape of Slack Web API responses that don't return a payload
+/// beyond the `ok`/`error` envelope.
+#[derive(Deserialize)]
+struct ApiResponse {
+    ok: bool,
+    error: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct Member {
+    id: String,
+    profile: Profile,
+}
+
+#[derive(Deserialize)]
+struct Profile {
+    display_name: Option<String>,
+    real_name: Option<String>,
+    email: Option<String>,
+}
@@ -0,0 +1,51 @@
+use std::path::Path;
+
+/// Which of the two notifications a dedup key refers to.
+#[derive(Clone, Copy)]
+pub enum NotificationKind {
+    Current,
+    Warning,
+}
+
+impl NotificationKind {
+    fn as_str(&self) -> &'static str {
+        match self {
+            NotificationKind::Current => "current",
+            NotificationKind::Warning => "warning",
+        }
+    }
+}
+
+/// Tracks which `(date, tag, kind)` notifications have already been sent,
+/// backed by an embedded `sled` database, so re-running the binary on the
+/// same day doesn't double-post under at-least-once scheduling.
+pub struct Store {
+    db: sled::Db,
+}
+
+impl Store {
+    pub fn open(path: &Path) -> sled::Result<Self> {
+        Ok(Store {
+            db: sled::open(path)?,
+        })
+    }
+
+    fn key(date: &str, tag: &str, kind: NotificationKind) -> String {
+        format!("{}\0{}\0{}", date, tag, kind.as_str())
+    }
+
+    /// Whether a notification for this `(date, tag, kind)` was already sent.
+    pub fn was_sent(&self, date: &str, tag: &str, kind: NotificationKind) -> bool {
+        self.db
+            .contains_key(Self::key(date, tag, kind))
+            .unwrap_or(false)
+    }
+
+    /// Records that a notification for this `(date, tag, kind)` was sent.
+    pub fn mark_sent(&self, date: &str, tag: &str, kind: NotificationKind) {
+        if let Err(err) = self.db.insert(Self::key(date, tag, kind), date.as_bytes()) {
+            eprintln!("Warning: failed to persist sent-notification state ({})", err);
+        }
+        let _ = self.db.flush();
+    }
+}